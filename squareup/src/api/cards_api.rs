@@ -0,0 +1,91 @@
+//! Save and manage payment cards on file for a customer.
+//!
+//! The Cards API lets you save a customer's card information, retrieved as a payment token from
+//! the Web Payments SDK, so it can be charged again in the future without the customer needing to
+//! re-enter their details.
+
+use crate::{
+    config::Configuration,
+    http::client::HttpClient,
+    models::{
+        errors::SquareApiError, CreateCardRequest, CreateCardResponse, DisableCardResponse,
+        ListCardsParameters, ListCardsResponse, RetrieveCardResponse,
+    },
+    SquareClient,
+};
+
+const DEFAULT_URI: &str = "/cards";
+
+/// Save and manage [Card] profiles on file for a customer.
+pub struct CardsApi {
+    /// App config information
+    config: Configuration,
+    /// HTTP Client for requests to the Cards API endpoints
+    http_client: HttpClient,
+}
+
+impl CardsApi {
+    /// Instantiates a new `CardsApi`
+    pub fn new(square_client: SquareClient) -> CardsApi {
+        CardsApi {
+            config: square_client.config,
+            http_client: square_client.http_client,
+        }
+    }
+
+    /// Retrieves a list of [Card]s owned by the account making the request.
+    ///
+    /// A max of 25 cards will be returned per call, and a cursor will be provided when the number
+    /// of results exceeds this limit.
+    pub async fn list_cards(
+        &self,
+        params: &ListCardsParameters,
+    ) -> Result<ListCardsResponse, SquareApiError> {
+        let url = format!("{}{}", &self.url(), params.to_query_string());
+        let response = self.http_client.get(&url).await?;
+
+        response.deserialize().await
+    }
+
+    /// Adds a [Card] on file to an existing merchant.
+    ///
+    /// The `source_id` is a payment token, generated by the
+    /// [Web Payments SDK](https://developer.squareup.com/docs/web-payments/add-card), that
+    /// represents the card's payment details.
+    pub async fn create_card(
+        &self,
+        body: &CreateCardRequest,
+    ) -> Result<CreateCardResponse, SquareApiError> {
+        let response = self.http_client.post_idempotent(&self.url(), body).await?;
+
+        response.deserialize().await
+    }
+
+    /// Retrieves details for a specific [Card].
+    pub async fn retrieve_card(
+        &self,
+        card_id: &str,
+    ) -> Result<RetrieveCardResponse, SquareApiError> {
+        let url = format!("{}/{}", &self.url(), card_id);
+        let response = self.http_client.get(&url).await?;
+
+        response.deserialize().await
+    }
+
+    /// Disables the [Card], preventing any further updates or charges.
+    ///
+    /// Disabling an already disabled card is allowed but has no effect.
+    pub async fn disable_card(
+        &self,
+        card_id: &str,
+    ) -> Result<DisableCardResponse, SquareApiError> {
+        let url = format!("{}/{}/disable", &self.url(), card_id);
+        let response = self.http_client.empty_post(&url).await?;
+
+        response.deserialize().await
+    }
+
+    fn url(&self) -> String {
+        format!("{}{}", &self.config.get_base_url(), DEFAULT_URI)
+    }
+}
@@ -4,14 +4,19 @@
 //! customers based on various criteria (including customer group membership). You can also use the
 //! API to sync contacts between your CRM system and Square.
 
+use futures::Stream;
+
 use crate::models::{
     AddGroupToCustomerResponse, DeleteCustomerParameters, RemoveGroupFromCustomerResponse,
 };
 use crate::{
     config::Configuration,
-    http::client::HttpClient,
+    http::{
+        client::HttpClient,
+        paginator::{paginate, PageResponse},
+    },
     models::{
-        errors::SquareApiError, CreateCustomerRequest, CreateCustomerResponse,
+        errors::SquareApiError, CreateCustomerRequest, CreateCustomerResponse, Customer,
         DeleteCustomerResponse, ListCustomersParameters, ListCustomersResponse,
         RetrieveCustomerResponse, SearchCustomersRequest, SearchCustomersResponse,
         UpdateCustomerRequest, UpdateCustomerResponse,
@@ -19,6 +24,30 @@ use crate::{
     SquareClient,
 };
 
+impl PageResponse for ListCustomersResponse {
+    type Item = Customer;
+
+    fn cursor(&self) -> Option<String> {
+        self.cursor.clone()
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.customers.unwrap_or_default()
+    }
+}
+
+impl PageResponse for SearchCustomersResponse {
+    type Item = Customer;
+
+    fn cursor(&self) -> Option<String> {
+        self.cursor.clone()
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.customers.unwrap_or_default()
+    }
+}
+
 const DEFAULT_URI: &str = "/customers";
 
 /// Create and manage [Customer] profiles and sync CRM systems with Square.
@@ -54,6 +83,23 @@ impl CustomersApi {
         response.deserialize().await
     }
 
+    /// Lists [Customer] profiles associated with a Square account, transparently following
+    /// pagination cursors.
+    ///
+    /// This is equivalent to calling [list_customers](Self::list_customers) in a loop and
+    /// re-issuing the request with each page's cursor, except the original `params.limit` is
+    /// preserved across every page.
+    pub fn list_customers_stream(
+        &self,
+        params: ListCustomersParameters,
+    ) -> impl Stream<Item = Result<Customer, SquareApiError>> + '_ {
+        paginate(move |cursor| {
+            let mut params = params.clone();
+            params.cursor = cursor;
+            async move { self.list_customers(&params).await }
+        })
+    }
+
     /// Creates a new [Customer] for a business.
     ///
     /// You must provide at least one of the following values in your request to this endpoint:
@@ -67,7 +113,7 @@ impl CustomersApi {
         &self,
         body: &CreateCustomerRequest,
     ) -> Result<CreateCustomerResponse, SquareApiError> {
-        let response = self.http_client.post(&self.url(), body).await?;
+        let response = self.http_client.post_idempotent(&self.url(), body).await?;
 
         response.deserialize().await
     }
@@ -92,6 +138,23 @@ impl CustomersApi {
         response.deserialize().await
     }
 
+    /// Searches the [Customer] profiles associated with a Square account, transparently
+    /// following pagination cursors.
+    ///
+    /// This is equivalent to calling [search_customers](Self::search_customers) in a loop and
+    /// re-issuing the request with each page's cursor, except the original `body.limit` is
+    /// preserved across every page.
+    pub fn search_customers_stream(
+        &self,
+        body: SearchCustomersRequest,
+    ) -> impl Stream<Item = Result<Customer, SquareApiError>> + '_ {
+        paginate(move |cursor| {
+            let mut body = body.clone();
+            body.cursor = cursor;
+            async move { self.search_customers(&body).await }
+        })
+    }
+
     /// Deletes a [Customer] profile from a business.
     ///
     /// This operation also unlinks any associated cards on file.
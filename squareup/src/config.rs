@@ -0,0 +1,76 @@
+//! Configuration shared by every API endpoint wrapper.
+
+use std::time::Duration;
+
+/// The Square environment a [Configuration] talks to.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Environment {
+    /// The production Square API.
+    Production,
+    /// The [sandbox](https://developer.squareup.com/docs/testing/sandbox) Square API, for testing
+    /// without moving real money.
+    Sandbox,
+}
+
+impl Environment {
+    fn base_url(self) -> &'static str {
+        match self {
+            Environment::Production => "https://connect.squareup.com/v2",
+            Environment::Sandbox => "https://connect.squareupsandbox.com/v2",
+        }
+    }
+}
+
+/// Controls whether, and how aggressively, [HttpClient](crate::http::client::HttpClient) retries
+/// a request after a transient failure.
+#[derive(Clone, Debug)]
+pub struct RetryConfiguration {
+    /// Whether automatic retries are enabled at all. Defaults to `true`.
+    pub enabled: bool,
+    /// The maximum number of attempts (including the initial one) made before giving up.
+    pub max_attempts: u32,
+    /// The backoff bound for the first retry; later retries double this, up to `max_elapsed`.
+    /// Each delay is chosen at random between half of the current bound and the full bound.
+    pub base_delay: Duration,
+    /// The maximum backoff delay between any two attempts. A delay taken from a server's
+    /// `Retry-After` header is used as given and is not capped by this value.
+    pub max_elapsed: Duration,
+}
+
+impl Default for RetryConfiguration {
+    fn default() -> Self {
+        RetryConfiguration {
+            enabled: true,
+            max_attempts: 3,
+            base_delay: Duration::from_millis(500),
+            max_elapsed: Duration::from_secs(30),
+        }
+    }
+}
+
+/// Configuration shared by every API endpoint wrapper: which Square environment to call, the
+/// access token to authenticate with, and the retry policy to apply to every request.
+#[derive(Clone, Debug)]
+pub struct Configuration {
+    /// The Square environment to send requests to.
+    pub environment: Environment,
+    /// The [personal access token or OAuth
+    /// token](https://developer.squareup.com/docs/build-basics/access-tokens) used to
+    /// authenticate every request.
+    pub access_token: String,
+    /// The retry policy applied by the [HttpClient](crate::http::client::HttpClient).
+    pub retry_configuration: RetryConfiguration,
+}
+
+impl Configuration {
+    /// Returns the base URL requests should be issued against, for example
+    /// `https://connect.squareup.com/v2`.
+    pub fn get_base_url(&self) -> String {
+        self.environment.base_url().to_string()
+    }
+
+    /// Returns the configured [RetryConfiguration].
+    pub fn get_retry_configuration(&self) -> RetryConfiguration {
+        self.retry_configuration.clone()
+    }
+}
@@ -0,0 +1,221 @@
+//! The low-level HTTP client every API endpoint wrapper issues requests through.
+
+use std::time::Duration;
+
+use rand::Rng;
+use reqwest::{Method, StatusCode};
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::{
+    config::{Configuration, RetryConfiguration},
+    models::errors::SquareApiError,
+};
+
+/// Whether a request is safe to retry automatically on a transient failure.
+///
+/// `GET`, `PUT`, and `DELETE` are idempotent by definition. A `POST` is only safe to retry when
+/// the caller has attached an [IdempotencyKey](crate::models::IdempotencyKey) to the body, since
+/// Square only guarantees deduplication in that case.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Idempotency {
+    /// Safe to retry.
+    Safe,
+    /// Not safe to retry automatically — a `POST` with no idempotency key.
+    Unsafe,
+}
+
+/// The wrapped response returned by every [HttpClient] request method.
+pub struct SquareResponse {
+    inner: reqwest::Response,
+}
+
+impl SquareResponse {
+    /// Deserializes the response body into `T`.
+    pub async fn deserialize<T: DeserializeOwned>(self) -> Result<T, SquareApiError> {
+        self.inner
+            .json()
+            .await
+            .map_err(|err| SquareApiError::new(err.to_string()))
+    }
+}
+
+/// Sends requests to the Square API on behalf of every endpoint wrapper (for example
+/// [CustomersApi](crate::api::customers_api::CustomersApi)), applying the configured retry
+/// policy along the way.
+#[derive(Clone, Debug)]
+pub struct HttpClient {
+    client: reqwest::Client,
+    access_token: String,
+    retry_configuration: RetryConfiguration,
+}
+
+impl HttpClient {
+    /// Instantiates a new `HttpClient` from the given [Configuration].
+    pub fn new(config: &Configuration) -> Self {
+        HttpClient {
+            client: reqwest::Client::new(),
+            access_token: config.access_token.clone(),
+            retry_configuration: config.get_retry_configuration(),
+        }
+    }
+
+    /// Issues a `GET` request to `url`.
+    pub async fn get(&self, url: &str) -> Result<SquareResponse, SquareApiError> {
+        self.execute_with_retry(Method::GET, url, None::<&()>, Idempotency::Safe)
+            .await
+    }
+
+    /// Issues a `POST` request to `url` with the given JSON body.
+    ///
+    /// This is never retried automatically, since a plain `POST` body carries no guarantee that
+    /// resending it is safe. Create-style endpoints whose body carries an
+    /// [IdempotencyKey](crate::models::IdempotencyKey) should use [post_idempotent](Self::post_idempotent)
+    /// instead, so a retry after a transient failure doesn't risk creating a duplicate resource.
+    pub async fn post<T: Serialize>(
+        &self,
+        url: &str,
+        body: &T,
+    ) -> Result<SquareResponse, SquareApiError> {
+        self.execute_with_retry(Method::POST, url, Some(body), Idempotency::Unsafe)
+            .await
+    }
+
+    /// Issues a `POST` request to `url` with the given JSON body, retrying automatically when
+    /// `body` carries an [IdempotencyKey](crate::models::IdempotencyKey).
+    ///
+    /// Square only guarantees that a `POST` is safe to resend when it carries an idempotency key,
+    /// so `body` is constrained to [Idempotent] and retry eligibility is determined per-request
+    /// from `body.idempotency_key()` rather than assumed for every `POST`.
+    pub async fn post_idempotent<T>(
+        &self,
+        url: &str,
+        body: &T,
+    ) -> Result<SquareResponse, SquareApiError>
+    where
+        T: Serialize + crate::models::Idempotent,
+    {
+        let idempotency = if body.idempotency_key().is_some() {
+            Idempotency::Safe
+        } else {
+            Idempotency::Unsafe
+        };
+
+        self.execute_with_retry(Method::POST, url, Some(body), idempotency)
+            .await
+    }
+
+    /// Issues a `POST` request to `url` with no body.
+    ///
+    /// This is never retried automatically: an empty-body `POST` carries no idempotency key, so
+    /// Square cannot guarantee deduplication if it is sent twice.
+    pub async fn empty_post(&self, url: &str) -> Result<SquareResponse, SquareApiError> {
+        self.execute_with_retry(Method::POST, url, None::<&()>, Idempotency::Unsafe)
+            .await
+    }
+
+    /// Issues a `PUT` request to `url` with the given JSON body.
+    pub async fn put<T: Serialize>(
+        &self,
+        url: &str,
+        body: &T,
+    ) -> Result<SquareResponse, SquareApiError> {
+        self.execute_with_retry(Method::PUT, url, Some(body), Idempotency::Safe)
+            .await
+    }
+
+    /// Issues a `PUT` request to `url` with no body.
+    pub async fn empty_put(&self, url: &str) -> Result<SquareResponse, SquareApiError> {
+        self.execute_with_retry(Method::PUT, url, None::<&()>, Idempotency::Safe)
+            .await
+    }
+
+    /// Issues a `DELETE` request to `url`.
+    pub async fn delete(&self, url: &str) -> Result<SquareResponse, SquareApiError> {
+        self.execute_with_retry(Method::DELETE, url, None::<&()>, Idempotency::Safe)
+            .await
+    }
+
+    /// Executes a request, retrying on connection errors and `429`/`5xx` responses when the
+    /// retry policy and the request's idempotency allow it.
+    async fn execute_with_retry<T: Serialize>(
+        &self,
+        method: Method,
+        url: &str,
+        body: Option<&T>,
+        idempotency: Idempotency,
+    ) -> Result<SquareResponse, SquareApiError> {
+        let retryable = self.retry_configuration.enabled && idempotency == Idempotency::Safe;
+        let max_attempts = if retryable {
+            self.retry_configuration.max_attempts.max(1)
+        } else {
+            1
+        };
+
+        let mut attempt = 0;
+        loop {
+            attempt += 1;
+
+            let mut request = self
+                .client
+                .request(method.clone(), url)
+                .bearer_auth(&self.access_token);
+            if let Some(body) = body {
+                request = request.json(body);
+            }
+
+            let outcome = request.send().await;
+
+            let should_retry = attempt < max_attempts
+                && match &outcome {
+                    Ok(response) => {
+                        let status = response.status();
+                        status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error()
+                    }
+                    Err(err) => err.is_connect() || err.is_timeout(),
+                };
+
+            if !should_retry {
+                let response = outcome.map_err(|err| SquareApiError::new(err.to_string()))?;
+
+                if !response.status().is_success() {
+                    return Err(SquareApiError::new(format!(
+                        "request to {url} failed with status {}",
+                        response.status()
+                    )));
+                }
+
+                return Ok(SquareResponse { inner: response });
+            }
+
+            let delay = retry_after(outcome.as_ref().ok())
+                .unwrap_or_else(|| backoff_with_jitter(attempt, &self.retry_configuration));
+            tokio::time::sleep(delay).await;
+        }
+    }
+}
+
+/// Reads and parses a `Retry-After` header (in seconds) from a response, if present.
+fn retry_after(response: Option<&reqwest::Response>) -> Option<Duration> {
+    let seconds = response?
+        .headers()
+        .get(reqwest::header::RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .parse::<u64>()
+        .ok()?;
+
+    Some(Duration::from_secs(seconds))
+}
+
+/// Computes the delay before the next attempt using exponential backoff with jitter, capped by
+/// `retry_configuration.max_elapsed`.
+fn backoff_with_jitter(attempt: u32, retry_configuration: &RetryConfiguration) -> Duration {
+    let exponential = retry_configuration
+        .base_delay
+        .saturating_mul(2u32.saturating_pow(attempt.saturating_sub(1)));
+    let capped = exponential.min(retry_configuration.max_elapsed);
+
+    let jitter_millis = rand::thread_rng().gen_range(0..=capped.as_millis() as u64 / 2);
+
+    capped / 2 + Duration::from_millis(jitter_millis)
+}
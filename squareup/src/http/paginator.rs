@@ -0,0 +1,52 @@
+//! A generic cursor-based paginator shared by every list-style endpoint.
+//!
+//! Every list and search endpoint in the crate returns a single page of results plus an optional
+//! `cursor` field that, when present, is fed back into the next request to fetch the following
+//! page. [paginate] turns that convention into a single [futures::Stream] so callers don't have
+//! to hand-roll the loop themselves.
+
+use futures::Stream;
+
+use crate::models::errors::SquareApiError;
+
+/// Implemented by list-style response bodies that carry a page of items plus an optional
+/// pagination cursor.
+pub(crate) trait PageResponse {
+    /// The type of item yielded by the page.
+    type Item;
+
+    /// The cursor to pass to the next request, or `None` if this was the last page.
+    fn cursor(&self) -> Option<String>;
+
+    /// Consumes the page, returning the items it carried.
+    fn into_items(self) -> Vec<Self::Item>;
+}
+
+/// Builds a [Stream] of items by repeatedly invoking `fetch_page` with the previous page's
+/// cursor, until a response is returned with no cursor.
+pub(crate) fn paginate<F, Fut, Page>(
+    mut fetch_page: F,
+) -> impl Stream<Item = Result<Page::Item, SquareApiError>>
+where
+    F: FnMut(Option<String>) -> Fut,
+    Fut: std::future::Future<Output = Result<Page, SquareApiError>>,
+    Page: PageResponse,
+{
+    async_stream::try_stream! {
+        let mut cursor = None;
+
+        loop {
+            let page = fetch_page(cursor).await?;
+            let next_cursor = page.cursor();
+
+            for item in page.into_items() {
+                yield item;
+            }
+
+            match next_cursor {
+                Some(c) => cursor = Some(c),
+                None => break,
+            }
+        }
+    }
+}
@@ -0,0 +1,54 @@
+//! Model struct for Card type
+
+use serde::{Deserialize, Serialize};
+
+use super::{enums::CardBrand, enums::CardType, Address};
+
+/// Represents the payment details of a card to be used for payments. These
+/// details are determined by the payment token generated by Web Payments SDK.
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Eq, PartialEq)]
+pub struct Card {
+    /// Unique ID for this [Card].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The card's brand.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub card_brand: Option<CardBrand>,
+    /// The last 4 digits of the card number.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_4: Option<String>,
+    /// The expiration month of the card, as an integer between 1 and 12.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exp_month: Option<i64>,
+    /// The four-digit year of the card's expiration date.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exp_year: Option<i64>,
+    /// The name of the cardholder.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cardholder_name: Option<String>,
+    /// The billing address for this [Card].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub billing_address: Option<Address>,
+    /// Uniquely identifies the card for this seller and all of its locations.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fingerprint: Option<String>,
+    /// The ID of the [Customer] linked to this [Card].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer_id: Option<String>,
+    /// The ID of the merchant associated with the [Card].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub merchant_id: Option<String>,
+    /// An optional user-defined reference ID that associates this [Card] with
+    /// another entity in an external system.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reference_id: Option<String>,
+    /// Indicates whether or not a card can be used for payments.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    /// The type of the card. Supported values are CREDIT, DEBIT, and UNKNOWN_CARD_TYPE.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub card_type: Option<CardType>,
+    /// Current version number of the [Card]. Increments with each change.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<i64>,
+}
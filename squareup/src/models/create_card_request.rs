@@ -0,0 +1,32 @@
+//! Model struct for CreateCardRequest type
+
+use serde::{Deserialize, Serialize};
+
+use super::{Card, Idempotent, IdempotencyKey};
+
+/// A request to create a [Card].
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Eq, PartialEq)]
+pub struct CreateCardRequest {
+    /// A unique string that identifies this `CreateCard` request. Keys can be any valid string
+    /// but must be unique for every `CreateCard` request.
+    ///
+    /// Retrying a request with the same key is always safe and will not create a duplicate
+    /// card; use [IdempotencyKey::new] to generate one automatically.
+    pub idempotency_key: IdempotencyKey,
+    /// The ID of the source that represents the card information to save, provided by
+    /// [Web Payments SDK](https://developer.squareup.com/docs/web-payments/add-card).
+    pub source_id: String,
+    /// An identifying token generated by
+    /// [Web Payments SDK](https://developer.squareup.com/docs/web-payments/add-card) buyer
+    /// verification (3D Secure/SCA), used to confirm the buyer's identity when adding the card.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verification_token: Option<String>,
+    /// The card to create.
+    pub card: Card,
+}
+
+impl Idempotent for CreateCardRequest {
+    fn idempotency_key(&self) -> Option<&IdempotencyKey> {
+        Some(&self.idempotency_key)
+    }
+}
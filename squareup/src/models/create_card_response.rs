@@ -0,0 +1,16 @@
+//! Response body struct for the [CreateCard](crate::api::cards_api::CardsApi::create_card) endpoint
+
+use serde::{Deserialize, Serialize};
+
+use super::{errors::Error, Card};
+
+/// This is a model struct for CreateCardResponse type.
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Eq, PartialEq)]
+pub struct CreateCardResponse {
+    /// Information on errors encountered during the request.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub errors: Option<Vec<Error>>,
+    /// The newly created [Card].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub card: Option<Card>,
+}
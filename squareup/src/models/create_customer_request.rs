@@ -0,0 +1,64 @@
+//! Request body struct for the [CreateCustomer](crate::api::customers_api::CustomersApi::create_customer) endpoint
+
+use serde::{Deserialize, Serialize};
+
+use super::{Address, Idempotent, IdempotencyKey};
+
+/// This is a model struct for CreateCustomerRequest type.
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Eq, PartialEq)]
+pub struct CreateCustomerRequest {
+    /// A unique string that identifies this `CreateCustomer` request. Keys can be any valid
+    /// string but must be unique for every `CreateCustomer` request.
+    ///
+    /// Retrying a request with the same key is always safe and will not create a duplicate
+    /// customer profile; use [IdempotencyKey::new] to generate one automatically.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub idempotency_key: Option<IdempotencyKey>,
+    /// The given name (that is, the first name) associated with the customer profile.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub given_name: Option<String>,
+    /// The family name (that is, the last name) associated with the customer profile.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub family_name: Option<String>,
+    /// A business name associated with the customer profile.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub company_name: Option<String>,
+    /// A nickname for the customer profile.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nickname: Option<String>,
+    /// The email address associated with the customer profile.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email_address: Option<String>,
+    /// The physical address associated with the customer profile.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<Address>,
+    /// The phone number associated with the customer profile.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone_number: Option<String>,
+    /// An optional second ID used to associate the customer profile with an entity in another
+    /// system.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reference_id: Option<String>,
+    /// A custom note associated with the customer profile.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+}
+
+impl CreateCustomerRequest {
+    /// Creates a new [CreateCustomerRequest] with a freshly generated [IdempotencyKey].
+    ///
+    /// At least one of `given_name`, `family_name`, `company_name`, `email_address`, or
+    /// `phone_number` must still be set on the returned request for it to be accepted.
+    pub fn new() -> Self {
+        CreateCustomerRequest {
+            idempotency_key: Some(IdempotencyKey::new()),
+            ..Default::default()
+        }
+    }
+}
+
+impl Idempotent for CreateCustomerRequest {
+    fn idempotency_key(&self) -> Option<&IdempotencyKey> {
+        self.idempotency_key.as_ref()
+    }
+}
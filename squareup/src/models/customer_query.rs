@@ -0,0 +1,278 @@
+//! A fluent builder for constructing [SearchCustomersRequest] query filters.
+
+use super::{enums::CustomerSortField, enums::SortOrder, SearchCustomersRequest, TimeRange};
+
+/// Specifies how to filter the search by the customer profile's creation source.
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize, Eq, PartialEq)]
+pub struct CustomerCreationSourceFilter {
+    /// The list of creation sources used as filtering criteria.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub values: Option<Vec<String>>,
+    /// Indicates whether the `values` are included in, or excluded from, the result.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rule: Option<CustomerInclusionExclusion>,
+}
+
+/// Indicates whether the filtered values should be included in, or excluded from, the result.
+#[derive(Clone, Copy, Debug, serde::Deserialize, serde::Serialize, Eq, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum CustomerInclusionExclusion {
+    /// Customers matching the filter values are included in the result.
+    Include,
+    /// Customers matching the filter values are excluded from the result.
+    Exclude,
+}
+
+/// A fuzzy or exact text match filter, shared by the `email_address`, `phone_number`, and
+/// `reference_id` filters on [CustomerFilter].
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize, Eq, PartialEq)]
+pub struct CustomerTextFilter {
+    /// A string to fuzzy match against.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fuzzy: Option<String>,
+    /// A string that must exactly match.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exact: Option<String>,
+}
+
+/// Specifies how to filter [Customer] group membership as part of a [CustomerFilter].
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize, Eq, PartialEq)]
+pub struct CustomerGroupIdsFilter {
+    /// The list of group IDs used as filtering criteria.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub values: Option<Vec<String>>,
+    /// Indicates whether the `values` are included in, or excluded from, the result.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rule: Option<CustomerInclusionExclusion>,
+}
+
+/// The set of supported query expressions used to search for [Customer] profiles.
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize, Eq, PartialEq)]
+pub struct CustomerFilter {
+    /// Filters by the source from which the customer profile was created.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub creation_source: Option<CustomerCreationSourceFilter>,
+    /// Filters by the time range the customer profile was created in.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<TimeRange>,
+    /// Filters by the time range the customer profile was last updated in.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<TimeRange>,
+    /// Filters by the customer's email address.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email_address: Option<CustomerTextFilter>,
+    /// Filters by the customer's phone number.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone_number: Option<CustomerTextFilter>,
+    /// Filters by the customer's reference ID.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reference_id: Option<CustomerTextFilter>,
+    /// Filters by the customer's group membership.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group_ids: Option<CustomerGroupIdsFilter>,
+}
+
+/// Specifies how [Customer] results should be sorted in a search response.
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, Eq, PartialEq)]
+pub struct CustomerSort {
+    /// Indicates the field to sort the results on.
+    pub field: CustomerSortField,
+    /// Indicates whether the results should be sorted in ascending or descending order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order: Option<SortOrder>,
+}
+
+/// The query used to search for [Customer] profiles, consisting of a [CustomerFilter] and a
+/// [CustomerSort].
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize, Eq, PartialEq)]
+pub struct CustomerQuery {
+    /// The filter criteria for the search query.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<CustomerFilter>,
+    /// Indicates how the results should be sorted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort: Option<CustomerSort>,
+}
+
+impl CustomerQuery {
+    /// Creates a [CustomerQueryBuilder] for fluently building a [SearchCustomersRequest].
+    pub fn builder() -> CustomerQueryBuilder {
+        CustomerQueryBuilder::default()
+    }
+}
+
+/// A fluent builder for a [SearchCustomersRequest], built up from a [CustomerQuery].
+#[derive(Clone, Debug, Default)]
+pub struct CustomerQueryBuilder {
+    filter: CustomerFilter,
+    sort: Option<CustomerSort>,
+    cursor: Option<String>,
+    limit: Option<i32>,
+}
+
+impl CustomerQueryBuilder {
+    /// Matches customer profiles created from one of the given sources.
+    pub fn created_from<I: IntoIterator<Item = S>, S: Into<String>>(
+        mut self,
+        sources: I,
+        rule: CustomerInclusionExclusion,
+    ) -> Self {
+        self.filter.creation_source = Some(CustomerCreationSourceFilter {
+            values: Some(sources.into_iter().map(Into::into).collect()),
+            rule: Some(rule),
+        });
+        self
+    }
+
+    /// Matches customer profiles created at or after the given point in time.
+    pub fn created_after(mut self, start_at: impl Into<String>) -> Self {
+        self.filter.created_at = Some(TimeRange {
+            start_at: Some(start_at.into()),
+            end_at: self.filter.created_at.and_then(|range| range.end_at),
+        });
+        self
+    }
+
+    /// Matches customer profiles created at or before the given point in time.
+    pub fn created_before(mut self, end_at: impl Into<String>) -> Self {
+        self.filter.created_at = Some(TimeRange {
+            start_at: self.filter.created_at.and_then(|range| range.start_at),
+            end_at: Some(end_at.into()),
+        });
+        self
+    }
+
+    /// Matches customer profiles updated at or after the given point in time.
+    pub fn updated_after(mut self, start_at: impl Into<String>) -> Self {
+        self.filter.updated_at = Some(TimeRange {
+            start_at: Some(start_at.into()),
+            end_at: self.filter.updated_at.and_then(|range| range.end_at),
+        });
+        self
+    }
+
+    /// Matches customer profiles updated at or before the given point in time.
+    pub fn updated_before(mut self, end_at: impl Into<String>) -> Self {
+        self.filter.updated_at = Some(TimeRange {
+            start_at: self.filter.updated_at.and_then(|range| range.start_at),
+            end_at: Some(end_at.into()),
+        });
+        self
+    }
+
+    /// Matches customer profiles whose email address fuzzy-matches the given string.
+    pub fn email_fuzzy(mut self, email: impl Into<String>) -> Self {
+        self.filter.email_address = Some(CustomerTextFilter {
+            fuzzy: Some(email.into()),
+            exact: None,
+        });
+        self
+    }
+
+    /// Matches customer profiles whose email address exactly matches the given string.
+    pub fn email_exact(mut self, email: impl Into<String>) -> Self {
+        self.filter.email_address = Some(CustomerTextFilter {
+            fuzzy: None,
+            exact: Some(email.into()),
+        });
+        self
+    }
+
+    /// Matches customer profiles whose phone number fuzzy-matches the given string.
+    pub fn phone_fuzzy(mut self, phone_number: impl Into<String>) -> Self {
+        self.filter.phone_number = Some(CustomerTextFilter {
+            fuzzy: Some(phone_number.into()),
+            exact: None,
+        });
+        self
+    }
+
+    /// Matches customer profiles whose phone number exactly matches the given string.
+    pub fn phone_exact(mut self, phone_number: impl Into<String>) -> Self {
+        self.filter.phone_number = Some(CustomerTextFilter {
+            fuzzy: None,
+            exact: Some(phone_number.into()),
+        });
+        self
+    }
+
+    /// Matches customer profiles whose reference ID fuzzy-matches the given string.
+    pub fn reference_id_fuzzy(mut self, reference_id: impl Into<String>) -> Self {
+        self.filter.reference_id = Some(CustomerTextFilter {
+            fuzzy: Some(reference_id.into()),
+            exact: None,
+        });
+        self
+    }
+
+    /// Matches customer profiles whose reference ID exactly matches the given string.
+    pub fn reference_id_exact(mut self, reference_id: impl Into<String>) -> Self {
+        self.filter.reference_id = Some(CustomerTextFilter {
+            fuzzy: None,
+            exact: Some(reference_id.into()),
+        });
+        self
+    }
+
+    /// Matches customer profiles that belong to one of the given groups.
+    pub fn in_groups<I: IntoIterator<Item = S>, S: Into<String>>(mut self, group_ids: I) -> Self {
+        self.filter.group_ids = Some(CustomerGroupIdsFilter {
+            values: Some(group_ids.into_iter().map(Into::into).collect()),
+            rule: Some(CustomerInclusionExclusion::Include),
+        });
+        self
+    }
+
+    /// Excludes customer profiles that belong to one of the given groups.
+    pub fn not_in_groups<I: IntoIterator<Item = S>, S: Into<String>>(
+        mut self,
+        group_ids: I,
+    ) -> Self {
+        self.filter.group_ids = Some(CustomerGroupIdsFilter {
+            values: Some(group_ids.into_iter().map(Into::into).collect()),
+            rule: Some(CustomerInclusionExclusion::Exclude),
+        });
+        self
+    }
+
+    /// Sorts the results by the given field and order.
+    pub fn sort_by(mut self, field: CustomerSortField, order: SortOrder) -> Self {
+        self.sort = Some(CustomerSort {
+            field,
+            order: Some(order),
+        });
+        self
+    }
+
+    /// Sets the pagination cursor for the request.
+    pub fn cursor(mut self, cursor: impl Into<String>) -> Self {
+        self.cursor = Some(cursor.into());
+        self
+    }
+
+    /// Sets the maximum number of results to return per page.
+    pub fn limit(mut self, limit: i32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Builds the [SearchCustomersRequest] from the accumulated filter and sort criteria.
+    pub fn build(self) -> SearchCustomersRequest {
+        let filter = self.filter;
+        let query = CustomerQuery {
+            filter: if filter == CustomerFilter::default() {
+                None
+            } else {
+                Some(filter)
+            },
+            sort: self.sort,
+        };
+
+        SearchCustomersRequest {
+            query: Some(query),
+            cursor: self.cursor,
+            limit: self.limit,
+            ..Default::default()
+        }
+    }
+}
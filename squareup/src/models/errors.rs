@@ -0,0 +1,35 @@
+//! Error types returned by the Square API and this crate's HTTP layer.
+
+use serde::{Deserialize, Serialize};
+
+/// An error that occurred while sending a request to, or receiving a response from, the Square
+/// API.
+#[derive(Clone, Debug, thiserror::Error, Eq, PartialEq)]
+#[error("{message}")]
+pub struct SquareApiError {
+    message: String,
+}
+
+impl SquareApiError {
+    /// Creates a new [SquareApiError] with the given message.
+    pub fn new(message: impl Into<String>) -> Self {
+        SquareApiError {
+            message: message.into(),
+        }
+    }
+}
+
+/// A single error returned in the `errors` array of a Square API response body.
+#[derive(Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+pub struct Error {
+    /// The high-level category for the error, for example `INVALID_REQUEST_ERROR`.
+    pub category: String,
+    /// The specific code identifying the error, for example `BAD_REQUEST`.
+    pub code: String,
+    /// A human-readable description of the error, for display or logging.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+    /// The name of the field in the request body that the error is associated with, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub field: Option<String>,
+}
@@ -0,0 +1,55 @@
+//! A client-generated key that lets Square safely retry a create-style request.
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// A unique key identifying a request made to a create-style endpoint, so that retrying the same
+/// request after a network failure is guaranteed not to create duplicate resources.
+///
+/// Square deduplicates requests by this key for up to 24 hours, so reusing the same key to retry
+/// a failed request is always safe, while reusing it for a genuinely different request is
+/// rejected.
+#[derive(Clone, Debug, Deserialize, Serialize, Eq, PartialEq, Hash)]
+#[serde(transparent)]
+pub struct IdempotencyKey(String);
+
+impl IdempotencyKey {
+    /// Generates a new, random [IdempotencyKey].
+    pub fn new() -> Self {
+        IdempotencyKey(Uuid::new_v4().to_string())
+    }
+}
+
+impl Default for IdempotencyKey {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<String> for IdempotencyKey {
+    fn from(key: String) -> Self {
+        IdempotencyKey(key)
+    }
+}
+
+impl AsRef<str> for IdempotencyKey {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for IdempotencyKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// Implemented by request bodies that carry an [IdempotencyKey].
+///
+/// The HTTP layer uses this to determine whether a `POST` is safe to retry automatically: a
+/// request that carries an idempotency key is guaranteed by Square to be deduplicated, so it can
+/// be retried after a transient failure without risking duplicate resources.
+pub trait Idempotent {
+    /// Returns the request's idempotency key, if one has been set.
+    fn idempotency_key(&self) -> Option<&IdempotencyKey>;
+}
@@ -0,0 +1,45 @@
+//! Parameters for the [ListCards](crate::api::cards_api::CardsApi::list_cards) endpoint
+
+/// Query parameters for the `ListCards` endpoint.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ListCardsParameters {
+    /// A pagination cursor returned by a previous call to this endpoint. Provide this to
+    /// retrieve the next set of results for the original query.
+    pub cursor: Option<String>,
+    /// Limit results to cards associated with the customer supplied.
+    pub customer_id: Option<String>,
+    /// Includes disabled cards. Defaults to `false`.
+    pub include_disabled: Option<bool>,
+    /// Sorts the returned list by when the card was created, in `ASC` or `DESC` order.
+    pub sort_order: Option<String>,
+    /// Limit results to cards whose `reference_id` matches the one supplied.
+    pub reference_id: Option<String>,
+}
+
+impl ListCardsParameters {
+    pub(crate) fn to_query_string(&self) -> String {
+        let mut params = vec![];
+
+        if let Some(cursor) = &self.cursor {
+            params.push(format!("cursor={cursor}"));
+        }
+        if let Some(customer_id) = &self.customer_id {
+            params.push(format!("customer_id={customer_id}"));
+        }
+        if let Some(include_disabled) = &self.include_disabled {
+            params.push(format!("include_disabled={include_disabled}"));
+        }
+        if let Some(sort_order) = &self.sort_order {
+            params.push(format!("sort_order={sort_order}"));
+        }
+        if let Some(reference_id) = &self.reference_id {
+            params.push(format!("reference_id={reference_id}"));
+        }
+
+        if params.is_empty() {
+            String::new()
+        } else {
+            format!("?{}", params.join("&"))
+        }
+    }
+}
@@ -0,0 +1,20 @@
+//! Response body struct for the [ListCards](crate::api::cards_api::CardsApi::list_cards) endpoint
+
+use serde::{Deserialize, Serialize};
+
+use super::{errors::Error, Card};
+
+/// This is a model struct for ListCardsResponse type.
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Eq, PartialEq)]
+pub struct ListCardsResponse {
+    /// Information on errors encountered during the request.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub errors: Option<Vec<Error>>,
+    /// The requested list of [Card]s.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cards: Option<Vec<Card>>,
+    /// When a response is truncated, it includes a cursor that you can use in a subsequent
+    /// request to fetch the next set of results.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+}
@@ -0,0 +1,29 @@
+//! Request body struct for the [SearchCustomers](crate::api::customers_api::CustomersApi::search_customers) endpoint
+
+use serde::{Deserialize, Serialize};
+
+use super::CustomerQuery;
+
+/// This is a model struct for SearchCustomersRequest type.
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Eq, PartialEq)]
+pub struct SearchCustomersRequest {
+    /// A pagination cursor returned by a previous call to this endpoint. Provide this to
+    /// retrieve the next set of results for the original query.
+    ///
+    /// See the [Pagination
+    /// guide](https://developer.squareup.com/docs/working-with-apis/pagination) for more
+    /// information.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    /// The maximum number of results to return in a single page.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i32>,
+    /// The filtering and sorting criteria for the search query. Build one with
+    /// [CustomerQuery::builder].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query: Option<CustomerQuery>,
+    /// Indicates whether to return the total count of matching customers in the `count` field of
+    /// the response.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<bool>,
+}
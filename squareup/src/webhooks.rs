@@ -0,0 +1,149 @@
+//! Verification of webhook notifications sent by Square.
+//!
+//! Square signs every webhook notification so a handler can confirm it actually came from
+//! Square and not a spoofed request. See the [webhooks
+//! documentation](https://developer.squareup.com/docs/webhooks/step3validate) for details on the
+//! signing scheme implemented here.
+
+use base64::Engine;
+use hmac::{Hmac, Mac};
+use serde::de::DeserializeOwned;
+use sha2::Sha256;
+use subtle::ConstantTimeEq;
+
+/// The name of the HTTP header Square sends the webhook signature in.
+pub const SIGNATURE_HEADER: &str = "x-square-hmacsha256-signature";
+
+/// An error verifying or deserializing a webhook notification.
+#[derive(Debug, thiserror::Error)]
+pub enum WebhookError {
+    /// `received_signature` did not match the signature computed from the notification URL and
+    /// raw request body.
+    #[error("webhook signature did not match the computed HMAC-SHA256 digest")]
+    SignatureMismatch,
+    /// The signature matched, but the raw body failed to deserialize into the target type.
+    #[error("failed to deserialize webhook body: {0}")]
+    Deserialize(#[from] serde_json::Error),
+}
+
+/// Verifies that `received_signature` is the signature Square would have produced for this
+/// webhook notification.
+///
+/// The message Square signs is the exact `notification_url` configured for the subscription,
+/// concatenated directly (with no separator) with the raw, unmodified request body bytes. The
+/// HMAC-SHA256 digest of that message, keyed by the subscription's signature key and
+/// base64-encoded, is sent in the `x-square-hmacsha256-signature` header as `received_signature`.
+///
+/// `raw_body` must be the untouched bytes of the request body exactly as received; re-serializing
+/// a deserialized struct will not reproduce the same bytes and will always fail verification.
+/// The comparison against `received_signature` is constant-time to avoid leaking information
+/// about the expected signature through response timing.
+pub fn verify_signature(
+    signature_key: &str,
+    notification_url: &str,
+    raw_body: &[u8],
+    received_signature: &str,
+) -> bool {
+    let mut mac = match Hmac::<Sha256>::new_from_slice(signature_key.as_bytes()) {
+        Ok(mac) => mac,
+        Err(_) => return false,
+    };
+
+    mac.update(notification_url.as_bytes());
+    mac.update(raw_body);
+
+    let expected_signature =
+        base64::engine::general_purpose::STANDARD.encode(mac.finalize().into_bytes());
+
+    expected_signature
+        .as_bytes()
+        .ct_eq(received_signature.as_bytes())
+        .into()
+}
+
+/// Verifies the signature of a raw webhook payload, then deserializes it into `T`.
+///
+/// Returns [WebhookError::SignatureMismatch] if the signature does not match, without attempting
+/// to deserialize the body.
+pub fn parse_and_verify<T: DeserializeOwned>(
+    signature_key: &str,
+    notification_url: &str,
+    raw_body: &[u8],
+    received_signature: &str,
+) -> Result<T, WebhookError> {
+    if !verify_signature(signature_key, notification_url, raw_body, received_signature) {
+        return Err(WebhookError::SignatureMismatch);
+    }
+
+    Ok(serde_json::from_slice(raw_body)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SIGNATURE_KEY: &str = "test_signature_key";
+    const NOTIFICATION_URL: &str = "https://example.com/webhook";
+    const BODY: &[u8] = br#"{"merchant_id":"M123","type":"location.updated"}"#;
+    // HMAC-SHA256(SIGNATURE_KEY, NOTIFICATION_URL || BODY), base64-encoded.
+    const VALID_SIGNATURE: &str = "D/3FZmfe0l2AqiWKgT5JZdDWpbWIDzQBFpaiVnUesGA=";
+
+    #[test]
+    fn verifies_a_matching_signature() {
+        assert!(verify_signature(
+            SIGNATURE_KEY,
+            NOTIFICATION_URL,
+            BODY,
+            VALID_SIGNATURE
+        ));
+    }
+
+    #[test]
+    fn rejects_a_tampered_body() {
+        let tampered: &[u8] =
+            br#"{"merchant_id":"M123","type":"location.updated","x":1}"#;
+
+        assert!(!verify_signature(
+            SIGNATURE_KEY,
+            NOTIFICATION_URL,
+            tampered,
+            VALID_SIGNATURE
+        ));
+    }
+
+    #[test]
+    fn rejects_the_wrong_signature_key() {
+        assert!(!verify_signature(
+            "wrong_key",
+            NOTIFICATION_URL,
+            BODY,
+            VALID_SIGNATURE
+        ));
+    }
+
+    #[test]
+    fn rejects_a_signature_of_the_wrong_length() {
+        assert!(!verify_signature(
+            SIGNATURE_KEY,
+            NOTIFICATION_URL,
+            BODY,
+            "too_short"
+        ));
+    }
+
+    #[test]
+    fn parse_and_verify_rejects_an_invalid_signature() {
+        let result: Result<serde_json::Value, _> =
+            parse_and_verify(SIGNATURE_KEY, NOTIFICATION_URL, BODY, "bogus_signature");
+
+        assert!(matches!(result, Err(WebhookError::SignatureMismatch)));
+    }
+
+    #[test]
+    fn parse_and_verify_deserializes_on_a_valid_signature() {
+        let result: serde_json::Value =
+            parse_and_verify(SIGNATURE_KEY, NOTIFICATION_URL, BODY, VALID_SIGNATURE).unwrap();
+
+        assert_eq!(result["merchant_id"], "M123");
+    }
+}